@@ -1,7 +1,94 @@
 //! # References
 //! - Operators on Fuzzy Sets: Zadeh and Einstein (by Hannes Gassert.)
 
-pub type Truth = f32;
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+/// A validated fuzzy truth value, guaranteed to lie in the closed interval `[0, 1]`.
+///
+/// Earlier revisions of this crate passed truths around as bare `f32`s and had every operator
+/// re-check for NaN/infinity/out-of-range inputs. `Truth` moves that validation to construction
+/// time, so operators can assume their inputs are sound and only need to guard against results
+/// that escape the domain (which they do, via [`Truth::clamped`]).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Truth(f32);
+
+impl Truth {
+    /// Constructs a `Truth` from a raw value, returning `None` unless it is finite and within
+    /// `[0, 1]`.
+    pub fn new(value: f32) -> Option<Truth> {
+        if value.is_nan() || value.is_infinite() { return None; }
+        if !(0.0..=1.0).contains(&value) { return None; }
+        Some(Truth(value))
+    }
+
+    /// Constructs a `Truth` from a raw value, clamping it into `[0, 1]`. Returns `None` only if
+    /// the value is NaN or infinite to begin with.
+    pub fn clamped(value: f32) -> Option<Truth> {
+        if value.is_nan() || value.is_infinite() { return None; }
+        Some(Truth(value.clamp(0.0, 1.0)))
+    }
+
+    /// Returns the raw `f32` this `Truth` wraps.
+    pub fn get(&self) -> f32 {
+        self.0
+    }
+}
+
+impl Deref for Truth {
+    type Target = f32;
+
+    fn deref(&self) -> &f32 {
+        &self.0
+    }
+}
+
+impl From<Truth> for f32 {
+    fn from(truth: Truth) -> f32 {
+        truth.0
+    }
+}
+
+impl TryFrom<f32> for Truth {
+    type Error = ();
+
+    /// Fallible conversion from a raw `f32`, equivalent to [`Truth::new`].
+    fn try_from(value: f32) -> Result<Truth, ()> {
+        Truth::new(value).ok_or(())
+    }
+}
+
+#[cfg(test)]
+mod truth_tests {
+    use super::Truth;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn new_rejects_out_of_range() {
+        assert!(Truth::new(-0.1).is_none());
+        assert!(Truth::new(1.1).is_none());
+        assert!(Truth::new(f32::NAN).is_none());
+    }
+
+    #[test]
+    fn clamped_clips_into_range() {
+        assert_eq!(Truth::clamped(1.5).unwrap().get(), 1.0);
+        assert_eq!(Truth::clamped(-1.5).unwrap().get(), 0.0);
+    }
+
+    #[test]
+    fn deref_and_from_expose_the_raw_value() {
+        let t = Truth::new(0.25).unwrap();
+        assert_eq!(*t, 0.25);
+        assert_eq!(f32::from(t), 0.25);
+    }
+
+    #[test]
+    fn try_from_mirrors_new() {
+        assert!(Truth::try_from(0.5).is_ok());
+        assert!(Truth::try_from(2.0).is_err());
+    }
+}
 
 /// Zadeh operators are best suited for logic operations that are exclusionary; membership in one
 /// set implies a non-membership in another set.
@@ -11,10 +98,7 @@ pub mod zadeh {
     /// Performs a Zadeh intersection of two truths. This is analogous to a boolean "or.".
     /// A and B are individual memberships of an item within classification A and classification B.
     pub fn min(a: Truth, b: Truth) -> Option<Truth> {
-        if a.is_nan() || b.is_nan() { return None; }
-        if a.is_infinite() || b.is_infinite() { return None; }
-
-        if a > b {
+        if a.get() > b.get() {
             Some(b)
         } else {
             Some(a)
@@ -30,10 +114,7 @@ pub mod zadeh {
     /// Performs a Zadeh union of two truths. This is analogous to a boolean "and.".
     /// A and B are individual memberships of an item within classification A and classification B.
     pub fn max(a: Truth, b: Truth) -> Option<Truth> {
-        if a.is_nan() || b.is_nan()           { return None; }
-        if a.is_infinite() || b.is_infinite() { return None; }
-
-        if a > b {
+        if a.get() > b.get() {
             Some(a)
         } else {
             Some(b)
@@ -54,13 +135,9 @@ pub mod einstein {
 
     /// Computes the Einstein product of two fuzzy set memberships.
     pub fn product(a: Truth, b: Truth) -> Option<Truth> {
-        if a.is_nan() || b.is_nan()           { return None; }
-        if a.is_infinite() || b.is_infinite() { return None; }
-
-        let result = (a * b) / (1.0 + ((1.0 - a) * (1.0 - b)))
-        ;
-        if result.is_nan() || result.is_infinite() { return None; }
-        Some(result)
+        let (a, b) = (a.get(), b.get());
+        let result = (a * b) / (1.0 + ((1.0 - a) * (1.0 - b)));
+        Truth::clamped(result)
     }
 
     #[inline(always)]
@@ -71,13 +148,9 @@ pub mod einstein {
 
     /// Computes the Einstein sum of two fuzzy set memberships.
     pub fn sum(a: Truth, b: Truth) -> Option<Truth> {
-        if a.is_nan() || b.is_nan()           { return None; }
-        if a.is_infinite() || b.is_infinite() { return None; }
-
+        let (a, b) = (a.get(), b.get());
         let result = (a + b) / (1.0 + (a * b));
-
-        if result.is_nan() || result.is_infinite() { return None; }
-        Some(result)
+        Truth::clamped(result)
     }
 
     #[inline(always)]
@@ -93,15 +166,13 @@ pub mod werner {
     /// Implement's Werner's "fuzzy and" operator, which functions as a type of
     /// "averaging" operator across fuzzy set memberships. Weight should be between zero and one.
     pub fn weighted_min(weight: f32, a: Truth, b: Truth) -> Option<Truth> {
-        // NB we aren't enforcing the weight's domain; it might be worth doing?
+        if weight.is_nan() || weight.is_infinite() { return None; }
+        if !(0.0..=1.0).contains(&weight) { return None; }
+
         match ::zadeh::min(a, b) {
             Some(x) => {
-                let result = ((weight * x) + ((1.0 - weight) * (a + b))) / 2.0;
-                if result.is_nan() || result.is_infinite() {
-                    None
-                } else {
-                    Some(result)
-                }
+                let result = ((weight * x.get()) + ((1.0 - weight) * (a.get() + b.get()))) / 2.0;
+                Truth::clamped(result)
             },
             None => None,
         }
@@ -112,4 +183,772 @@ pub mod werner {
     pub fn fuzzy_and(weight: f32, a: Truth, b: Truth) -> Option<Truth> {
         weighted_min(weight, a, b)
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn rejects_weight_outside_unit_range() {
+            let (a, b) = (Truth::new(0.5).unwrap(), Truth::new(0.5).unwrap());
+            assert!(weighted_min(37.0, a, b).is_none());
+            assert!(weighted_min(-0.1, a, b).is_none());
+        }
+
+        #[test]
+        fn accepts_weight_within_unit_range() {
+            let (a, b) = (Truth::new(0.2).unwrap(), Truth::new(0.8).unwrap());
+            assert!(weighted_min(0.5, a, b).is_some());
+        }
+    }
+}
+
+/// Generalizes the Zadeh and Einstein operators into a family of interchangeable t-norm /
+/// t-conorm pairs, so callers can pick a semantics without hard-coding a specific module's
+/// functions.
+pub mod tnorm {
+    use ::Truth;
+
+    /// The Gödel family. Its t-norm/t-conorm pair is identical to the Zadeh min/max operators.
+    pub mod godel {
+        use ::Truth;
+
+        /// Gödel t-norm: `min(a, b)`.
+        pub fn intersection(a: Truth, b: Truth) -> Option<Truth> {
+            ::zadeh::min(a, b)
+        }
+
+        #[inline(always)]
+        /// Semantic sugar for the Gödel t-norm.
+        pub fn and(a: Truth, b: Truth) -> Option<Truth> {
+            intersection(a, b)
+        }
+
+        /// Gödel t-conorm: `max(a, b)`.
+        pub fn union(a: Truth, b: Truth) -> Option<Truth> {
+            ::zadeh::max(a, b)
+        }
+
+        #[inline(always)]
+        /// Semantic sugar for the Gödel t-conorm.
+        pub fn or(a: Truth, b: Truth) -> Option<Truth> {
+            union(a, b)
+        }
+    }
+
+    /// The algebraic product family.
+    pub mod product {
+        use ::Truth;
+
+        /// Product t-norm: `a * b`.
+        pub fn intersection(a: Truth, b: Truth) -> Option<Truth> {
+            Truth::clamped(a.get() * b.get())
+        }
+
+        #[inline(always)]
+        /// Semantic sugar for the product t-norm.
+        pub fn and(a: Truth, b: Truth) -> Option<Truth> {
+            intersection(a, b)
+        }
+
+        /// Product t-conorm: `a + b - a*b`.
+        pub fn union(a: Truth, b: Truth) -> Option<Truth> {
+            let (a, b) = (a.get(), b.get());
+            Truth::clamped(a + b - (a * b))
+        }
+
+        #[inline(always)]
+        /// Semantic sugar for the product t-conorm.
+        pub fn or(a: Truth, b: Truth) -> Option<Truth> {
+            union(a, b)
+        }
+    }
+
+    /// The Łukasiewicz family.
+    pub mod lukasiewicz {
+        use ::Truth;
+
+        /// Łukasiewicz t-norm: `max(0, a + b - 1)`.
+        pub fn intersection(a: Truth, b: Truth) -> Option<Truth> {
+            Truth::clamped(a.get() + b.get() - 1.0)
+        }
+
+        #[inline(always)]
+        /// Semantic sugar for the Łukasiewicz t-norm.
+        pub fn and(a: Truth, b: Truth) -> Option<Truth> {
+            intersection(a, b)
+        }
+
+        /// Łukasiewicz t-conorm: `min(1, a + b)`.
+        pub fn union(a: Truth, b: Truth) -> Option<Truth> {
+            Truth::clamped(a.get() + b.get())
+        }
+
+        #[inline(always)]
+        /// Semantic sugar for the Łukasiewicz t-conorm.
+        pub fn or(a: Truth, b: Truth) -> Option<Truth> {
+            union(a, b)
+        }
+    }
+
+    /// Lets callers be generic over the choice of t-norm / t-conorm family instead of hard-coding
+    /// a specific module's functions (e.g. `zadeh::min`).
+    pub trait TNorm {
+        /// The t-norm (intersection-like) operator.
+        fn and(&self, a: Truth, b: Truth) -> Option<Truth>;
+        /// The t-conorm (union-like) operator.
+        fn or(&self, a: Truth, b: Truth) -> Option<Truth>;
+    }
+
+    /// Selects the Gödel family (equivalent to `Zadeh`).
+    pub struct Godel;
+
+    impl TNorm for Godel {
+        fn and(&self, a: Truth, b: Truth) -> Option<Truth> { godel::and(a, b) }
+        fn or(&self, a: Truth, b: Truth) -> Option<Truth> { godel::or(a, b) }
+    }
+
+    /// Selects the algebraic product family.
+    pub struct Product;
+
+    impl TNorm for Product {
+        fn and(&self, a: Truth, b: Truth) -> Option<Truth> { product::and(a, b) }
+        fn or(&self, a: Truth, b: Truth) -> Option<Truth> { product::or(a, b) }
+    }
+
+    /// Selects the Łukasiewicz family.
+    pub struct Lukasiewicz;
+
+    impl TNorm for Lukasiewicz {
+        fn and(&self, a: Truth, b: Truth) -> Option<Truth> { lukasiewicz::and(a, b) }
+        fn or(&self, a: Truth, b: Truth) -> Option<Truth> { lukasiewicz::or(a, b) }
+    }
+
+    /// Selects the Einstein family from the `einstein` module.
+    pub struct Einstein;
+
+    impl TNorm for Einstein {
+        fn and(&self, a: Truth, b: Truth) -> Option<Truth> { ::einstein::product(a, b) }
+        fn or(&self, a: Truth, b: Truth) -> Option<Truth> { ::einstein::sum(a, b) }
+    }
+
+    /// Selects the Zadeh family from the `zadeh` module.
+    pub struct Zadeh;
+
+    impl TNorm for Zadeh {
+        fn and(&self, a: Truth, b: Truth) -> Option<Truth> { ::zadeh::min(a, b) }
+        fn or(&self, a: Truth, b: Truth) -> Option<Truth> { ::zadeh::max(a, b) }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use Truth;
+
+        #[test]
+        fn godel_matches_zadeh() {
+            let (a, b) = (Truth::new(0.3).unwrap(), Truth::new(0.7).unwrap());
+            assert_eq!(godel::intersection(a, b).unwrap().get(), ::zadeh::min(a, b).unwrap().get());
+            assert_eq!(godel::union(a, b).unwrap().get(), ::zadeh::max(a, b).unwrap().get());
+        }
+
+        #[test]
+        fn product_tnorm_and_tconorm() {
+            let (a, b) = (Truth::new(0.5).unwrap(), Truth::new(0.5).unwrap());
+            assert_eq!(product::intersection(a, b).unwrap().get(), 0.25);
+            assert_eq!(product::union(a, b).unwrap().get(), 0.75);
+        }
+
+        #[test]
+        fn lukasiewicz_tconorm_clamps_at_one() {
+            let (a, b) = (Truth::new(0.8).unwrap(), Truth::new(0.8).unwrap());
+            assert_eq!(lukasiewicz::union(a, b).unwrap().get(), 1.0);
+        }
+
+        #[test]
+        fn lukasiewicz_tnorm_clamps_at_zero() {
+            let (a, b) = (Truth::new(0.2).unwrap(), Truth::new(0.2).unwrap());
+            assert_eq!(lukasiewicz::intersection(a, b).unwrap().get(), 0.0);
+        }
+
+        #[test]
+        fn trait_impls_delegate_to_their_family_module() {
+            let (a, b) = (Truth::new(0.5).unwrap(), Truth::new(0.5).unwrap());
+            assert_eq!(Godel.and(a, b).unwrap().get(), godel::and(a, b).unwrap().get());
+            assert_eq!(Product.or(a, b).unwrap().get(), product::or(a, b).unwrap().get());
+            assert_eq!(Lukasiewicz.and(a, b).unwrap().get(), lukasiewicz::and(a, b).unwrap().get());
+        }
+
+        #[test]
+        fn and_is_the_t_norm_not_the_t_conorm() {
+            let (a, b) = (Truth::new(0.2).unwrap(), Truth::new(0.8).unwrap());
+            assert_eq!(Godel.and(a, b).unwrap().get(), 0.2);
+            assert_eq!(Godel.or(a, b).unwrap().get(), 0.8);
+            assert!((Product.and(a, b).unwrap().get() - 0.16).abs() < 1e-6);
+            assert_eq!(Lukasiewicz.and(a, b).unwrap().get(), 0.0);
+        }
+
+        #[test]
+        fn einstein_and_zadeh_traits_match_their_modules_intersection_and_union() {
+            let (a, b) = (Truth::new(0.3).unwrap(), Truth::new(0.7).unwrap());
+            assert_eq!(Zadeh.and(a, b).unwrap().get(), ::zadeh::min(a, b).unwrap().get());
+            assert_eq!(Zadeh.or(a, b).unwrap().get(), ::zadeh::max(a, b).unwrap().get());
+            assert_eq!(Einstein.and(a, b).unwrap().get(), ::einstein::product(a, b).unwrap().get());
+            assert_eq!(Einstein.or(a, b).unwrap().get(), ::einstein::sum(a, b).unwrap().get());
+        }
+    }
+}
+
+/// Fuzzy implication operators. These evaluate how strongly an antecedent `a` implies a
+/// consequent `b`, which is what gives a fuzzy rule like "if C1 then C2" its firing strength.
+pub mod implication {
+    use ::Truth;
+
+    /// Kleene-Dienes implication: `max(1 - a, b)`.
+    pub fn kleene_dienes(a: Truth, b: Truth) -> Option<Truth> {
+        let (a, b) = (a.get(), b.get());
+        Truth::clamped((1.0 - a).max(b))
+    }
+
+    /// Łukasiewicz implication: `min(1, 1 - a + b)`.
+    pub fn lukasiewicz(a: Truth, b: Truth) -> Option<Truth> {
+        let (a, b) = (a.get(), b.get());
+        Truth::clamped(1.0 - a + b)
+    }
+
+    /// Gödel implication: `1` if `a <= b`, otherwise `b`.
+    pub fn godel(a: Truth, b: Truth) -> Option<Truth> {
+        let result = if a.get() <= b.get() { 1.0 } else { b.get() };
+        Truth::clamped(result)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn kleene_dienes_full_antecedent_yields_consequent() {
+            let (a, b) = (Truth::new(1.0).unwrap(), Truth::new(0.4).unwrap());
+            assert_eq!(kleene_dienes(a, b).unwrap().get(), 0.4);
+        }
+
+        #[test]
+        fn lukasiewicz_clamps_at_one() {
+            let (a, b) = (Truth::new(0.2).unwrap(), Truth::new(0.9).unwrap());
+            assert_eq!(lukasiewicz(a, b).unwrap().get(), 1.0);
+        }
+
+        #[test]
+        fn godel_is_one_when_antecedent_at_most_consequent() {
+            let (a, b) = (Truth::new(0.3).unwrap(), Truth::new(0.7).unwrap());
+            assert_eq!(godel(a, b).unwrap().get(), 1.0);
+        }
+
+        #[test]
+        fn godel_is_consequent_when_antecedent_exceeds_it() {
+            let (a, b) = (Truth::new(0.7).unwrap(), Truth::new(0.3).unwrap());
+            assert_eq!(godel(a, b).unwrap().get(), 0.3);
+        }
+    }
+}
+
+/// Linguistic hedges, which modify a single membership degree the way "very" or "somewhat"
+/// modify a concept in approximate reasoning (e.g. "very tall" from "tall").
+pub mod hedge {
+    use ::Truth;
+
+    /// Concentration: `a * a`. Sharpens a membership, the fuzzy analogue of "very".
+    pub fn very(a: Truth) -> Option<Truth> {
+        power(a, 2.0)
+    }
+
+    /// Dilation: `sqrt(a)`. Softens a membership, the fuzzy analogue of "somewhat".
+    pub fn somewhat(a: Truth) -> Option<Truth> {
+        Truth::clamped(a.get().sqrt())
+    }
+
+    /// Standard fuzzy complement: `1 - a`.
+    pub fn not(a: Truth) -> Option<Truth> {
+        Truth::clamped(1.0 - a.get())
+    }
+
+    /// General concentration/dilation hedge: `a.powf(p)`. `very` and `somewhat` are the `p = 2.0`
+    /// and `p = 0.5` cases; callers can supply any other exponent for a custom hedge.
+    pub fn power(a: Truth, p: f32) -> Option<Truth> {
+        if p.is_nan() || p.is_infinite() { return None; }
+        Truth::clamped(a.get().powf(p))
+    }
+
+    /// Sugeno complement: `(1 - a) / (1 + lambda * a)`, for `lambda > -1`. Generalizes the
+    /// standard complement, which is the `lambda = 0` case.
+    pub fn sugeno(a: Truth, lambda: f32) -> Option<Truth> {
+        if lambda.is_nan() || lambda.is_infinite() { return None; }
+        if lambda <= -1.0 { return None; }
+
+        let a = a.get();
+        Truth::clamped((1.0 - a) / (1.0 + (lambda * a)))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn very_sharpens_membership() {
+            let a = Truth::new(0.5).unwrap();
+            assert_eq!(very(a).unwrap().get(), 0.25);
+        }
+
+        #[test]
+        fn somewhat_softens_membership() {
+            let a = Truth::new(0.25).unwrap();
+            assert_eq!(somewhat(a).unwrap().get(), 0.5);
+        }
+
+        #[test]
+        fn not_is_the_standard_complement() {
+            let a = Truth::new(0.3).unwrap();
+            assert_eq!(not(a).unwrap().get(), 0.7);
+        }
+
+        #[test]
+        fn sugeno_at_lambda_zero_matches_not() {
+            let a = Truth::new(0.3).unwrap();
+            assert_eq!(sugeno(a, 0.0).unwrap().get(), not(a).unwrap().get());
+        }
+
+        #[test]
+        fn sugeno_rejects_lambda_at_or_below_negative_one() {
+            let a = Truth::new(0.3).unwrap();
+            assert!(sugeno(a, -1.0).is_none());
+        }
+    }
+}
+
+/// Membership functions turn a crisp input `x` into a fuzzy membership degree, which is how a
+/// linguistic concept (e.g. "tall") is defined over a numeric domain in the first place.
+pub mod membership {
+    use ::Truth;
+
+    /// Lets callers be generic over the choice of membership function instead of hard-coding a
+    /// specific shape (e.g. `triangular`), mirroring how `tnorm::TNorm` abstracts over t-norm
+    /// families.
+    pub trait MembershipFunction {
+        /// Computes the membership degree of `x`. A NaN or infinite `x` is treated as zero
+        /// membership rather than failing. The shape's `new` validates its parameters, but the
+        /// fields are public, so a shape built directly is not checked; parameters that its `new`
+        /// would reject also give zero membership.
+        fn degree(&self, x: f32) -> Truth;
+    }
+
+    fn zero() -> Truth {
+        Truth::clamped(0.0).unwrap()
+    }
+
+    /// Triangular membership function, rising linearly from `a` to the peak at `b` and falling
+    /// linearly from `b` to `c`. Requires `a <= b <= c`.
+    pub fn triangular(x: f32, a: f32, b: f32, c: f32) -> Option<Truth> {
+        if x.is_nan() || a.is_nan() || b.is_nan() || c.is_nan() { return None; }
+        if x.is_infinite() || a.is_infinite() || b.is_infinite() || c.is_infinite() { return None; }
+        if !(a <= b && b <= c) { return None; }
+
+        let result = if (a == b && x == a) || (b == c && x == c) {
+            1.0
+        } else if x <= a || x >= c {
+            0.0
+        } else if x <= b {
+            (x - a) / (b - a)
+        } else {
+            (c - x) / (c - b)
+        };
+        Truth::clamped(result)
+    }
+
+    /// Trapezoidal membership function, rising linearly from `a` to `b`, holding full membership
+    /// from `b` to `c`, then falling linearly from `c` to `d`. Requires `a <= b <= c <= d`.
+    pub fn trapezoidal(x: f32, a: f32, b: f32, c: f32, d: f32) -> Option<Truth> {
+        if x.is_nan() || a.is_nan() || b.is_nan() || c.is_nan() || d.is_nan() { return None; }
+        if x.is_infinite() || a.is_infinite() || b.is_infinite() || c.is_infinite() || d.is_infinite() { return None; }
+        if !(a <= b && b <= c && c <= d) { return None; }
+
+        let result = if (a == b && x == a) || (c == d && x == d) {
+            1.0
+        } else if x <= a || x >= d {
+            0.0
+        } else if x < b {
+            (x - a) / (b - a)
+        } else if x <= c {
+            1.0
+        } else {
+            (d - x) / (d - c)
+        };
+        Truth::clamped(result)
+    }
+
+    /// Gaussian membership function centered on `mean`, with spread controlled by `sigma`.
+    /// Requires `sigma > 0`.
+    pub fn gaussian(x: f32, mean: f32, sigma: f32) -> Option<Truth> {
+        if x.is_nan() || mean.is_nan() || sigma.is_nan() { return None; }
+        if x.is_infinite() || mean.is_infinite() || sigma.is_infinite() { return None; }
+        if sigma <= 0.0 { return None; }
+
+        let delta = x - mean;
+        let result = (-(delta * delta) / (2.0 * sigma * sigma)).exp();
+        Truth::clamped(result)
+    }
+
+    /// Sigmoid membership function, crossing `0.5` at `midpoint` with the given `slope`. Positive
+    /// `slope` rises left-to-right; negative `slope` falls left-to-right.
+    pub fn sigmoid(x: f32, slope: f32, midpoint: f32) -> Option<Truth> {
+        if x.is_nan() || slope.is_nan() || midpoint.is_nan() { return None; }
+        if x.is_infinite() || slope.is_infinite() || midpoint.is_infinite() { return None; }
+
+        let result = 1.0 / (1.0 + (-slope * (x - midpoint)).exp());
+        Truth::clamped(result)
+    }
+
+    /// Selects the triangular shape, peaking at `b` over the support `[a, c]`.
+    pub struct Triangular { pub a: f32, pub b: f32, pub c: f32 }
+
+    impl Triangular {
+        /// Constructs a `Triangular`, returning `None` unless `a`, `b` and `c` are finite and
+        /// `a <= b <= c`.
+        pub fn new(a: f32, b: f32, c: f32) -> Option<Triangular> {
+            if a.is_nan() || b.is_nan() || c.is_nan() { return None; }
+            if a.is_infinite() || b.is_infinite() || c.is_infinite() { return None; }
+            if !(a <= b && b <= c) { return None; }
+            Some(Triangular { a, b, c })
+        }
+    }
+
+    impl MembershipFunction for Triangular {
+        fn degree(&self, x: f32) -> Truth {
+            triangular(x, self.a, self.b, self.c).unwrap_or_else(zero)
+        }
+    }
+
+    /// Selects the trapezoidal shape, holding full membership over `[b, c]` within the support
+    /// `[a, d]`.
+    pub struct Trapezoidal { pub a: f32, pub b: f32, pub c: f32, pub d: f32 }
+
+    impl Trapezoidal {
+        /// Constructs a `Trapezoidal`, returning `None` unless `a`, `b`, `c` and `d` are finite
+        /// and `a <= b <= c <= d`.
+        pub fn new(a: f32, b: f32, c: f32, d: f32) -> Option<Trapezoidal> {
+            if a.is_nan() || b.is_nan() || c.is_nan() || d.is_nan() { return None; }
+            if a.is_infinite() || b.is_infinite() || c.is_infinite() || d.is_infinite() { return None; }
+            if !(a <= b && b <= c && c <= d) { return None; }
+            Some(Trapezoidal { a, b, c, d })
+        }
+    }
+
+    impl MembershipFunction for Trapezoidal {
+        fn degree(&self, x: f32) -> Truth {
+            trapezoidal(x, self.a, self.b, self.c, self.d).unwrap_or_else(zero)
+        }
+    }
+
+    /// Selects the Gaussian shape, centered on `mean` with spread `sigma`.
+    pub struct Gaussian { pub mean: f32, pub sigma: f32 }
+
+    impl Gaussian {
+        /// Constructs a `Gaussian`, returning `None` unless `mean` and `sigma` are finite and
+        /// `sigma > 0`.
+        pub fn new(mean: f32, sigma: f32) -> Option<Gaussian> {
+            if mean.is_nan() || sigma.is_nan() { return None; }
+            if mean.is_infinite() || sigma.is_infinite() { return None; }
+            if sigma <= 0.0 { return None; }
+            Some(Gaussian { mean, sigma })
+        }
+    }
+
+    impl MembershipFunction for Gaussian {
+        fn degree(&self, x: f32) -> Truth {
+            gaussian(x, self.mean, self.sigma).unwrap_or_else(zero)
+        }
+    }
+
+    /// Selects the sigmoid shape, crossing `0.5` at `midpoint` with the given `slope`.
+    pub struct Sigmoid { pub slope: f32, pub midpoint: f32 }
+
+    impl Sigmoid {
+        /// Constructs a `Sigmoid`, returning `None` unless `slope` and `midpoint` are finite.
+        pub fn new(slope: f32, midpoint: f32) -> Option<Sigmoid> {
+            if slope.is_nan() || midpoint.is_nan() { return None; }
+            if slope.is_infinite() || midpoint.is_infinite() { return None; }
+            Some(Sigmoid { slope, midpoint })
+        }
+    }
+
+    impl MembershipFunction for Sigmoid {
+        fn degree(&self, x: f32) -> Truth {
+            sigmoid(x, self.slope, self.midpoint).unwrap_or_else(zero)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn triangular_peaks_at_b() {
+            assert_eq!(triangular(5.0, 0.0, 5.0, 10.0).unwrap().get(), 1.0);
+            assert_eq!(triangular(0.0, 0.0, 5.0, 10.0).unwrap().get(), 0.0);
+            assert_eq!(triangular(10.0, 0.0, 5.0, 10.0).unwrap().get(), 0.0);
+        }
+
+        #[test]
+        fn triangular_rejects_unordered_params() {
+            assert!(triangular(0.0, 5.0, 0.0, 10.0).is_none());
+        }
+
+        #[test]
+        fn trapezoidal_holds_plateau() {
+            assert_eq!(trapezoidal(5.0, 0.0, 2.0, 8.0, 10.0).unwrap().get(), 1.0);
+        }
+
+        #[test]
+        fn triangular_peaks_at_degenerate_edges() {
+            assert_eq!(triangular(2.0, 2.0, 2.0, 10.0).unwrap().get(), 1.0);
+            assert_eq!(triangular(10.0, 0.0, 10.0, 10.0).unwrap().get(), 1.0);
+        }
+
+        #[test]
+        fn trapezoidal_peaks_at_degenerate_edges() {
+            assert_eq!(trapezoidal(2.0, 2.0, 2.0, 8.0, 10.0).unwrap().get(), 1.0);
+            assert_eq!(trapezoidal(10.0, 0.0, 2.0, 10.0, 10.0).unwrap().get(), 1.0);
+        }
+
+        #[test]
+        fn gaussian_peaks_at_mean() {
+            assert_eq!(gaussian(3.0, 3.0, 1.0).unwrap().get(), 1.0);
+        }
+
+        #[test]
+        fn membership_function_trait_matches_free_function() {
+            let shape = Triangular::new(0.0, 5.0, 10.0).unwrap();
+            assert_eq!(shape.degree(5.0).get(), triangular(5.0, 0.0, 5.0, 10.0).unwrap().get());
+        }
+    }
+}
+
+/// OWA (Ordered Weighted Averaging) aggregates a tuple of truths into a single truth, weighting
+/// each value's *rank* (largest, second largest, ...) rather than which input it came from. This
+/// generalizes both the Zadeh min/max operators and a plain average, depending on the weights
+/// chosen.
+pub mod owa {
+    use ::Truth;
+
+    /// Tolerance used when checking that `weights` sums to `1.0`, to absorb `f32` rounding.
+    const WEIGHT_SUM_EPSILON: f32 = 1e-5;
+
+    /// Aggregates `values` using `weights`. Both slices must be the same non-zero length, each
+    /// weight must lie in `[0, 1]`, and the weights must sum to `1.0` (within a small epsilon) or
+    /// `None` is returned. Values are sorted in descending order before being paired with
+    /// `weights` in order, so `weights[0]` is always applied to the largest value.
+    pub fn owa(weights: &[f32], values: &[Truth]) -> Option<Truth> {
+        if values.is_empty() || values.len() != weights.len() { return None; }
+        if weights.iter().any(|w| w.is_nan() || w.is_infinite()) { return None; }
+        if weights.iter().any(|&w| !(0.0..=1.0).contains(&w)) { return None; }
+
+        let weight_sum: f32 = weights.iter().sum();
+        if (weight_sum - 1.0).abs() > WEIGHT_SUM_EPSILON { return None; }
+
+        let mut sorted: Vec<f32> = values.iter().map(|v| v.get()).collect();
+        sorted.sort_by(|a, b| b.partial_cmp(a).unwrap());
+
+        let result: f32 = sorted.iter().zip(weights.iter()).map(|(v, w)| v * w).sum();
+        Truth::clamped(result)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn rejects_weights_not_summing_to_one() {
+            let values = [Truth::new(1.0).unwrap(), Truth::new(1.0).unwrap(), Truth::new(0.0).unwrap()];
+            assert!(owa(&[0.5, 0.5, 0.5], &values).is_none());
+        }
+
+        #[test]
+        fn rejects_weight_outside_unit_range() {
+            let values = [Truth::new(1.0).unwrap(), Truth::new(0.0).unwrap()];
+            assert!(owa(&[1.5, -0.5], &values).is_none());
+        }
+
+        #[test]
+        fn weights_apply_to_ranked_values() {
+            let values = [Truth::new(0.2).unwrap(), Truth::new(0.9).unwrap(), Truth::new(0.5).unwrap()];
+            let result = owa(&[0.5, 0.3, 0.2], &values).unwrap();
+            assert_eq!(result.get(), (0.9 * 0.5) + (0.5 * 0.3) + (0.2 * 0.2));
+        }
+    }
+}
+
+/// Defuzzification converts a fuzzy output set, sampled as parallel `domain`/`membership` slices,
+/// back into a single crisp value.
+pub mod defuzzify {
+    use ::Truth;
+
+    /// Centroid (center of gravity): the mean `domain` value, weighted by `membership`.
+    pub fn centroid(domain: &[f32], membership: &[Truth]) -> Option<f32> {
+        if domain.is_empty() || domain.len() != membership.len() { return None; }
+        if domain.iter().any(|x| x.is_nan() || x.is_infinite()) { return None; }
+
+        let weight_sum: f32 = membership.iter().map(|m| m.get()).sum();
+        if weight_sum == 0.0 { return None; }
+
+        let numerator: f32 = domain.iter().zip(membership.iter()).map(|(x, m)| x * m.get()).sum();
+        let result = numerator / weight_sum;
+        if result.is_nan() || result.is_infinite() { None } else { Some(result) }
+    }
+
+    /// Bisector: the domain point that splits the cumulative membership area in half, i.e. the
+    /// smallest `domain` value (by ascending order) at which the running membership sum reaches
+    /// half of the total.
+    pub fn bisector(domain: &[f32], membership: &[Truth]) -> Option<f32> {
+        if domain.is_empty() || domain.len() != membership.len() { return None; }
+        if domain.iter().any(|x| x.is_nan() || x.is_infinite()) { return None; }
+
+        let total: f32 = membership.iter().map(|m| m.get()).sum();
+        if total == 0.0 { return None; }
+
+        let mut sorted: Vec<(f32, f32)> = domain.iter().zip(membership.iter())
+            .map(|(&x, &m)| (x, m.get()))
+            .collect();
+        sorted.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        let half = total / 2.0;
+        let mut running = 0.0;
+        for &(x, m) in &sorted {
+            running += m;
+            if running >= half {
+                return Some(x);
+            }
+        }
+
+        sorted.last().map(|&(x, _)| x)
+    }
+
+    /// Mean of maximum: the mean `domain` value among the samples that attain the highest
+    /// membership.
+    pub fn mean_of_maximum(domain: &[f32], membership: &[Truth]) -> Option<f32> {
+        if domain.is_empty() || domain.len() != membership.len() { return None; }
+        if domain.iter().any(|x| x.is_nan() || x.is_infinite()) { return None; }
+        if membership.iter().map(|m| m.get()).sum::<f32>() == 0.0 { return None; }
+
+        let peak = membership.iter().map(|m| m.get()).fold(0.0f32, f32::max);
+        let (sum, count) = domain.iter().zip(membership.iter())
+            .filter(|&(_, m)| m.get() == peak)
+            .fold((0.0, 0u32), |(sum, count), (&x, _)| (sum + x, count + 1));
+
+        if count == 0 { None } else { Some(sum / count as f32) }
+    }
+
+    /// Smallest of maximum: the smallest `domain` value among the samples that attain the
+    /// highest membership.
+    pub fn smallest_of_maximum(domain: &[f32], membership: &[Truth]) -> Option<f32> {
+        if domain.is_empty() || domain.len() != membership.len() { return None; }
+        if domain.iter().any(|x| x.is_nan() || x.is_infinite()) { return None; }
+        if membership.iter().map(|m| m.get()).sum::<f32>() == 0.0 { return None; }
+
+        let peak = membership.iter().map(|m| m.get()).fold(0.0f32, f32::max);
+        domain.iter().zip(membership.iter())
+            .filter(|&(_, m)| m.get() == peak)
+            .map(|(&x, _)| x)
+            .fold(None, |acc, x| match acc {
+                None => Some(x),
+                Some(current) => Some(current.min(x)),
+            })
+    }
+
+    /// Largest of maximum: the largest `domain` value among the samples that attain the highest
+    /// membership.
+    pub fn largest_of_maximum(domain: &[f32], membership: &[Truth]) -> Option<f32> {
+        if domain.is_empty() || domain.len() != membership.len() { return None; }
+        if domain.iter().any(|x| x.is_nan() || x.is_infinite()) { return None; }
+        if membership.iter().map(|m| m.get()).sum::<f32>() == 0.0 { return None; }
+
+        let peak = membership.iter().map(|m| m.get()).fold(0.0f32, f32::max);
+        domain.iter().zip(membership.iter())
+            .filter(|&(_, m)| m.get() == peak)
+            .map(|(&x, _)| x)
+            .fold(None, |acc, x| match acc {
+                None => Some(x),
+                Some(current) => Some(current.max(x)),
+            })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn centroid_of_symmetric_set_is_the_middle() {
+            let domain = [0.0, 10.0];
+            let membership = [Truth::new(1.0).unwrap(), Truth::new(1.0).unwrap()];
+            assert_eq!(centroid(&domain, &membership).unwrap(), 5.0);
+        }
+
+        #[test]
+        fn centroid_rejects_mismatched_lengths() {
+            let domain = [0.0, 10.0];
+            let membership = [Truth::new(1.0).unwrap()];
+            assert!(centroid(&domain, &membership).is_none());
+        }
+
+        #[test]
+        fn bisector_splits_cumulative_area_in_half() {
+            let domain = [0.0, 1.0, 2.0, 3.0];
+            let membership = [
+                Truth::new(1.0).unwrap(),
+                Truth::new(1.0).unwrap(),
+                Truth::new(1.0).unwrap(),
+                Truth::new(1.0).unwrap(),
+            ];
+            assert_eq!(bisector(&domain, &membership).unwrap(), 1.0);
+        }
+
+        #[test]
+        fn mean_of_maximum_averages_the_plateau() {
+            let domain = [0.0, 1.0, 2.0];
+            let membership = [
+                Truth::new(0.2).unwrap(),
+                Truth::new(1.0).unwrap(),
+                Truth::new(1.0).unwrap(),
+            ];
+            assert_eq!(mean_of_maximum(&domain, &membership).unwrap(), 1.5);
+        }
+
+        #[test]
+        fn smallest_and_largest_of_maximum_bracket_the_plateau() {
+            let domain = [0.0, 1.0, 2.0];
+            let membership = [
+                Truth::new(1.0).unwrap(),
+                Truth::new(0.5).unwrap(),
+                Truth::new(1.0).unwrap(),
+            ];
+            assert_eq!(smallest_of_maximum(&domain, &membership).unwrap(), 0.0);
+            assert_eq!(largest_of_maximum(&domain, &membership).unwrap(), 2.0);
+        }
+
+        #[test]
+        fn maximum_methods_reject_an_all_zero_membership_set() {
+            let domain = [0.0, 5.0];
+            let membership = [Truth::new(0.0).unwrap(), Truth::new(0.0).unwrap()];
+            assert!(mean_of_maximum(&domain, &membership).is_none());
+            assert!(smallest_of_maximum(&domain, &membership).is_none());
+            assert!(largest_of_maximum(&domain, &membership).is_none());
+        }
+
+        #[test]
+        fn maximum_methods_reject_mismatched_lengths() {
+            let domain = [0.0, 5.0];
+            let membership = [Truth::new(1.0).unwrap()];
+            assert!(mean_of_maximum(&domain, &membership).is_none());
+            assert!(smallest_of_maximum(&domain, &membership).is_none());
+            assert!(largest_of_maximum(&domain, &membership).is_none());
+        }
+    }
 }